@@ -21,7 +21,7 @@ mod settings;
 pub use incoming::{CertificateMetadata, MaybeTlsIncomingStream, MaybeTlsListener};
 pub use maybe_tls::MaybeTls;
 pub use settings::{
-    MaybeTlsSettings, TlsConfig, TlsEnableableConfig, TlsSettings, TlsSourceConfig,
+    MaybeTlsSettings, TlsConfig, TlsEnableableConfig, TlsSettings, TlsSourceConfig, TlsVersion,
     PEM_START_MARKER, TEST_PEM_CA_PATH, TEST_PEM_CLIENT_CRT_PATH, TEST_PEM_CLIENT_KEY_PATH,
     TEST_PEM_CRT_PATH, TEST_PEM_INTERMEDIATE_CA_PATH, TEST_PEM_KEY_PATH,
 };
@@ -104,6 +104,8 @@ pub enum TlsError {
     SetSni { source: ErrorStack },
     #[snafu(display("Error setting ALPN protocols: {}", source))]
     SetAlpnProtocols { source: ErrorStack },
+    #[snafu(display("Error setting the minimum TLS version: {}", source))]
+    SetMinProtoVersion { source: ErrorStack },
     #[snafu(display(
         "Error encoding ALPN protocols, could not encode length as u8: {}",
         source
@@ -9,7 +9,10 @@ use lookup::lookup_v2::OptionalValuePath;
 use openssl::{
     pkcs12::{ParsedPkcs12_2, Pkcs12},
     pkey::{PKey, Private},
-    ssl::{select_next_proto, AlpnError, ConnectConfiguration, SslContextBuilder, SslVerifyMode},
+    ssl::{
+        select_next_proto, AlpnError, ConnectConfiguration, SslContextBuilder, SslVerifyMode,
+        SslVersion,
+    },
     stack::Stack,
     x509::{store::X509StoreBuilder, X509},
 };
@@ -20,8 +23,8 @@ use super::{
     AddCertToStoreSnafu, AddExtraChainCertSnafu, CaStackPushSnafu, DerExportSnafu,
     EncodeAlpnProtocolsSnafu, FileOpenFailedSnafu, FileReadFailedSnafu, MaybeTls, NewCaStackSnafu,
     NewStoreBuilderSnafu, ParsePkcs12Snafu, Pkcs12Snafu, PrivateKeyParseSnafu, Result,
-    SetAlpnProtocolsSnafu, SetCertificateSnafu, SetPrivateKeySnafu, SetVerifyCertSnafu, TlsError,
-    TlsIdentitySnafu, X509ParseSnafu,
+    SetAlpnProtocolsSnafu, SetCertificateSnafu, SetMinProtoVersionSnafu, SetPrivateKeySnafu,
+    SetVerifyCertSnafu, TlsError, TlsIdentitySnafu, X509ParseSnafu,
 };
 
 pub const PEM_START_MARKER: &str = "-----BEGIN ";
@@ -114,6 +117,13 @@ pub struct TlsConfig {
     #[configurable(metadata(docs::examples = "h2"))]
     pub alpn_protocols: Option<Vec<String>>,
 
+    /// Sets the minimum TLS protocol version.
+    ///
+    /// Peers that cannot negotiate at least this version are rejected during the handshake. If
+    /// unset, the default minimum of the TLS library is used.
+    #[configurable(metadata(docs::advanced))]
+    pub min_tls_version: Option<TlsVersion>,
+
     /// Absolute path to an additional CA certificate file.
     ///
     /// The certificate must be in the DER or PEM (X.509) format. Additionally, the certificate can be provided as an inline string in PEM format.
@@ -158,6 +168,38 @@ pub struct TlsConfig {
     pub server_name: Option<String>,
 }
 
+/// TLS protocol version.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TlsVersion {
+    /// TLS 1.0.
+    #[serde(rename = "TLSv1.0")]
+    Tls10,
+
+    /// TLS 1.1.
+    #[serde(rename = "TLSv1.1")]
+    Tls11,
+
+    /// TLS 1.2.
+    #[serde(rename = "TLSv1.2")]
+    Tls12,
+
+    /// TLS 1.3.
+    #[serde(rename = "TLSv1.3")]
+    Tls13,
+}
+
+impl From<TlsVersion> for SslVersion {
+    fn from(version: TlsVersion) -> Self {
+        match version {
+            TlsVersion::Tls10 => SslVersion::TLS1,
+            TlsVersion::Tls11 => SslVersion::TLS1_1,
+            TlsVersion::Tls12 => SslVersion::TLS1_2,
+            TlsVersion::Tls13 => SslVersion::TLS1_3,
+        }
+    }
+}
+
 impl TlsConfig {
     pub fn test_config() -> Self {
         Self {
@@ -177,6 +219,7 @@ pub struct TlsSettings {
     authorities: Vec<X509>,
     pub(super) identity: Option<IdentityStore>, // openssl::pkcs12::ParsedPkcs12 doesn't impl Clone yet
     alpn_protocols: Option<Vec<u8>>,
+    min_tls_version: Option<TlsVersion>,
     server_name: Option<String>,
 }
 
@@ -212,6 +255,7 @@ impl TlsSettings {
             authorities: options.load_authorities()?,
             identity: options.load_identity()?,
             alpn_protocols: options.parse_alpn_protocols()?,
+            min_tls_version: options.min_tls_version,
             server_name: options.server_name.clone(),
         })
     }
@@ -342,6 +386,12 @@ impl TlsSettings {
             }
         }
 
+        if let Some(version) = self.min_tls_version {
+            context
+                .set_min_proto_version(Some(version.into()))
+                .context(SetMinProtoVersionSnafu)?;
+        }
+
         Ok(())
     }
 
@@ -678,6 +728,18 @@ mod test {
         assert_eq!(settings.alpn_protocols, Some(vec![2, 104, 50]));
     }
 
+    #[test]
+    fn parse_min_tls_version() {
+        let options: TlsConfig = toml::from_str(r#"min_tls_version = "TLSv1.3""#).unwrap();
+        assert_eq!(options.min_tls_version, Some(TlsVersion::Tls13));
+        let settings =
+            TlsSettings::from_options(Some(&options)).expect("Failed to parse min_tls_version");
+        assert_eq!(settings.min_tls_version, Some(TlsVersion::Tls13));
+
+        toml::from_str::<TlsConfig>(r#"min_tls_version = "TLSv1.4""#)
+            .expect_err("Unknown TLS version should be rejected");
+    }
+
     #[test]
     fn from_options_pkcs12() {
         let _provider = openssl::provider::Provider::try_load(None, "legacy", true).unwrap();
@@ -8,7 +8,10 @@ use http::{
 use hyper::{
     body::{Body, HttpBody},
     client,
-    client::{Client, HttpConnector},
+    client::{
+        connect::{capture_connection, CaptureConnection, HttpInfo},
+        Client, HttpConnector,
+    },
 };
 use hyper_openssl::HttpsConnector;
 use hyper_proxy::ProxyConnector;
@@ -19,6 +22,8 @@ use std::{
     collections::HashMap,
     fmt,
     net::SocketAddr,
+    num::NonZeroUsize,
+    sync::{Arc, Mutex},
     task::{Context, Poll},
     time::Duration,
 };
@@ -77,6 +82,7 @@ pub struct HttpClient<B = Body> {
     client: Client<HttpProxyConnector, B>,
     user_agent: HeaderValue,
     proxy_connector: HttpProxyConnector,
+    connection_request_limit: Option<Arc<ConnectionRequestLimit>>,
 }
 
 impl<B> HttpClient<B>
@@ -109,9 +115,26 @@ where
             client,
             user_agent,
             proxy_connector,
+            connection_request_limit: None,
         })
     }
 
+    pub fn new_with_pool_config(
+        tls_settings: impl Into<MaybeTlsSettings>,
+        proxy_config: &ProxyConfig,
+        pool_config: &HttpClientPoolConfig,
+    ) -> Result<HttpClient<B>, HttpError> {
+        let mut client = HttpClient::new_with_custom_client(
+            tls_settings,
+            proxy_config,
+            &mut pool_config.client_builder(),
+        )?;
+        client.connection_request_limit = pool_config
+            .max_requests_per_connection
+            .map(|max_requests| Arc::new(ConnectionRequestLimit::new(max_requests)));
+        Ok(client)
+    }
+
     pub fn send(
         &self,
         mut request: Request<B>,
@@ -124,6 +147,11 @@ where
 
         emit!(http_client::AboutToSendHttpRequest { request: &request });
 
+        let connection_request_limit = self
+            .connection_request_limit
+            .clone()
+            .map(|limit| (limit, capture_connection(&mut request)));
+
         let response = self.client.request(request);
 
         let fut = async move {
@@ -151,6 +179,10 @@ where
                 response: &response,
                 roundtrip
             });
+
+            if let Some((limit, connection)) = connection_request_limit {
+                limit.record(&response, &connection);
+            }
             Ok(response)
         }
         .instrument(span.clone().or_current());
@@ -250,6 +282,7 @@ impl<B> Clone for HttpClient<B> {
             client: self.client.clone(),
             user_agent: self.user_agent.clone(),
             proxy_connector: self.proxy_connector.clone(),
+            connection_request_limit: self.connection_request_limit.clone(),
         }
     }
 }
@@ -434,6 +467,91 @@ impl Default for KeepaliveConfig {
     }
 }
 
+/// Configuration of the HTTP client connection pool.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct HttpClientPoolConfig {
+    /// The maximum number of idle connections kept open per host.
+    ///
+    /// Idle connections are reused by later requests, avoiding the cost of a new TCP connection
+    /// and TLS handshake. If unset, the number of idle connections is not limited.
+    #[configurable(metadata(docs::examples = 16))]
+    pub max_idle_per_host: Option<usize>,
+
+    /// The amount of time an idle connection is kept open before it is closed.
+    ///
+    /// If unset, idle connections are closed after 90 seconds.
+    #[configurable(metadata(docs::examples = 30))]
+    #[configurable(metadata(docs::type_unit = "seconds"))]
+    #[configurable(metadata(docs::human_name = "Idle Timeout"))]
+    pub idle_timeout_secs: Option<u64>,
+
+    /// The maximum number of requests sent over a connection before it is closed.
+    ///
+    /// Closing connections periodically avoids errors from long-lived connections that have gone
+    /// stale, such as ones reset by the server. If unset, connections are reused indefinitely.
+    #[configurable(metadata(docs::examples = 1000))]
+    pub max_requests_per_connection: Option<NonZeroUsize>,
+}
+
+impl HttpClientPoolConfig {
+    /// Creates a client builder with the hyper pool settings applied.
+    ///
+    /// The builder does not apply `max_requests_per_connection`, which is enforced by the client
+    /// itself. Use [`HttpClient::new_with_pool_config`] to apply all of the settings.
+    pub fn client_builder(&self) -> client::Builder {
+        let mut builder = Client::builder();
+        if let Some(max_idle_per_host) = self.max_idle_per_host {
+            builder.pool_max_idle_per_host(max_idle_per_host);
+        }
+        if let Some(idle_timeout_secs) = self.idle_timeout_secs {
+            builder.pool_idle_timeout(Duration::from_secs(idle_timeout_secs));
+        }
+        builder
+    }
+}
+
+/// Counts the requests sent over each connection, and retires connections which have reached the
+/// maximum number of requests.
+///
+/// hyper doesn't identify its connections, so they are keyed by their local and remote
+/// addresses. The count of a connection closed before reaching the limit is kept until a later
+/// connection reuses the same addresses, which then retires early.
+#[derive(Debug)]
+struct ConnectionRequestLimit {
+    max_requests: NonZeroUsize,
+    requests: Mutex<HashMap<(SocketAddr, SocketAddr), usize>>,
+}
+
+impl ConnectionRequestLimit {
+    fn new(max_requests: NonZeroUsize) -> Self {
+        Self {
+            max_requests,
+            requests: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Records a response received over the connection, poisoning the connection once it has
+    /// served the maximum number of requests so that the pool doesn't reuse it.
+    fn record(&self, response: &Response<Body>, connection: &CaptureConnection) {
+        let Some(info) = response.extensions().get::<HttpInfo>() else {
+            return;
+        };
+        let key = (info.local_addr(), info.remote_addr());
+
+        let mut requests = self.requests.lock().expect("Lock should not be poisoned");
+        let count = requests.entry(key).or_default();
+        *count += 1;
+        if *count >= self.max_requests.get() {
+            requests.remove(&key);
+            if let Some(connected) = connection.connection_metadata().as_ref() {
+                connected.poison();
+            }
+        }
+    }
+}
+
 /// A layer that limits the maximum duration of a client connection. It does so by adding a
 /// `Connection: close` header to the response if `max_connection_duration` time has elapsed
 /// since `start_reference`.
@@ -609,16 +727,60 @@ pub type QueryParameters = HashMap<String, QueryParameterValue>;
 
 #[cfg(test)]
 mod tests {
-    use std::convert::Infallible;
+    use std::{
+        convert::Infallible,
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+    };
 
     use hyper::{server::conn::AddrStream, service::make_service_fn, Server};
+    use openssl::ssl::{Ssl, SslAcceptor, SslFiletype, SslMethod, SslVersion};
     use proptest::prelude::*;
+    use tokio::net::TcpListener;
+    use tokio_openssl::SslStream;
     use tower::ServiceBuilder;
 
-    use crate::test_util::next_addr;
+    use crate::{
+        test_util::next_addr,
+        tls::{
+            TlsConfig, TlsEnableableConfig, TlsSettings, TlsVersion, TEST_PEM_CA_PATH,
+            TEST_PEM_CLIENT_CRT_PATH, TEST_PEM_CLIENT_KEY_PATH, TEST_PEM_CRT_PATH,
+            TEST_PEM_KEY_PATH,
+        },
+    };
 
     use super::*;
 
+    /// Spawns an HTTPS server with the given TLS options, returning the URI to reach it.
+    async fn spawn_https_server(options: TlsConfig) -> String {
+        let addr = next_addr();
+        let tls = MaybeTlsSettings::from_config(
+            Some(&TlsEnableableConfig {
+                enabled: Some(true),
+                options,
+            }),
+            true,
+        )
+        .unwrap();
+        let listener = tls.bind(&addr).await.unwrap();
+
+        let make_svc = make_service_fn(|_| async {
+            Ok::<_, Infallible>(tower::service_fn(|_req: Request<Body>| async {
+                Ok::<Response<Body>, Infallible>(Response::new(Body::empty()))
+            }))
+        });
+        tokio::spawn(async move {
+            Server::builder(hyper::server::accept::from_stream(listener.accept_stream()))
+                .serve(make_svc)
+                .await
+                .unwrap();
+        });
+
+        format!("https://localhost:{}/", addr.port())
+    }
+
     #[test]
     fn test_default_request_headers_defaults() {
         let user_agent = HeaderValue::from_static("vector");
@@ -864,4 +1026,258 @@ mod tests {
         let response = client.send(req).await.unwrap();
         assert_eq!(response.headers().get("Connection"), None);
     }
+
+    #[tokio::test]
+    async fn test_client_certificate_presented_to_server() {
+        let uri = spawn_https_server(TlsConfig {
+            verify_certificate: Some(true),
+            ..TlsConfig::test_config()
+        })
+        .await;
+
+        // The server requires a client certificate, so the handshake fails without one.
+        let tls = TlsSettings::from_options(Some(&TlsConfig {
+            ca_file: Some(TEST_PEM_CA_PATH.into()),
+            ..Default::default()
+        }))
+        .unwrap();
+        let client = HttpClient::new(tls, &ProxyConfig::default()).unwrap();
+        let req = Request::get(&uri).body(Body::empty()).unwrap();
+        assert!(client.send(req).await.is_err());
+
+        let tls = TlsSettings::from_options(Some(&TlsConfig {
+            ca_file: Some(TEST_PEM_CA_PATH.into()),
+            crt_file: Some(TEST_PEM_CLIENT_CRT_PATH.into()),
+            key_file: Some(TEST_PEM_CLIENT_KEY_PATH.into()),
+            ..Default::default()
+        }))
+        .unwrap();
+        let client = HttpClient::new(tls, &ProxyConfig::default()).unwrap();
+        let req = Request::get(&uri).body(Body::empty()).unwrap();
+        let response = client.send(req).await.unwrap();
+        assert!(response.status().is_success());
+    }
+
+    #[tokio::test]
+    async fn test_verify_certificate_disabled() {
+        // The server certificate is signed by the test CA, which the client does not trust.
+        let uri = spawn_https_server(TlsConfig::test_config()).await;
+
+        let client = HttpClient::new(None, &ProxyConfig::default()).unwrap();
+        let req = Request::get(&uri).body(Body::empty()).unwrap();
+        assert!(client.send(req).await.is_err());
+
+        let tls = TlsSettings::from_options(Some(&TlsConfig {
+            verify_certificate: Some(false),
+            ..Default::default()
+        }))
+        .unwrap();
+        let client = HttpClient::new(tls, &ProxyConfig::default()).unwrap();
+        let req = Request::get(&uri).body(Body::empty()).unwrap();
+        let response = client.send(req).await.unwrap();
+        assert!(response.status().is_success());
+    }
+
+    #[tokio::test]
+    async fn test_min_tls_version_rejects_older_server() {
+        // Spawn a server which cannot negotiate anything newer than TLS 1.2.
+        let addr = next_addr();
+        let listener = TcpListener::bind(&addr).await.unwrap();
+        let mut acceptor = SslAcceptor::mozilla_intermediate_v5(SslMethod::tls()).unwrap();
+        acceptor
+            .set_certificate_chain_file(TEST_PEM_CRT_PATH)
+            .unwrap();
+        acceptor
+            .set_private_key_file(TEST_PEM_KEY_PATH, SslFiletype::PEM)
+            .unwrap();
+        acceptor
+            .set_max_proto_version(Some(SslVersion::TLS1_2))
+            .unwrap();
+        let acceptor = acceptor.build();
+        tokio::spawn(async move {
+            loop {
+                let (stream, _) = listener.accept().await.unwrap();
+                let ssl = Ssl::new(acceptor.context()).unwrap();
+                let mut stream = SslStream::new(ssl, stream).unwrap();
+                tokio::spawn(async move {
+                    if std::pin::Pin::new(&mut stream).accept().await.is_ok() {
+                        let svc = tower::service_fn(|_req: Request<Body>| async {
+                            Ok::<Response<Body>, Infallible>(Response::new(Body::empty()))
+                        });
+                        _ = hyper::server::conn::Http::new()
+                            .serve_connection(stream, svc)
+                            .await;
+                    }
+                });
+            }
+        });
+        let uri = format!("https://localhost:{}/", addr.port());
+
+        let tls = TlsSettings::from_options(Some(&TlsConfig {
+            ca_file: Some(TEST_PEM_CA_PATH.into()),
+            min_tls_version: Some(TlsVersion::Tls13),
+            ..Default::default()
+        }))
+        .unwrap();
+        let client = HttpClient::new(tls, &ProxyConfig::default()).unwrap();
+        let req = Request::get(&uri).body(Body::empty()).unwrap();
+        assert!(client.send(req).await.is_err());
+
+        let tls = TlsSettings::from_options(Some(&TlsConfig {
+            ca_file: Some(TEST_PEM_CA_PATH.into()),
+            min_tls_version: Some(TlsVersion::Tls12),
+            ..Default::default()
+        }))
+        .unwrap();
+        let client = HttpClient::new(tls, &ProxyConfig::default()).unwrap();
+        let req = Request::get(&uri).body(Body::empty()).unwrap();
+        let response = client.send(req).await.unwrap();
+        assert!(response.status().is_success());
+    }
+
+    #[tokio::test]
+    async fn test_alpn_prefers_http2() {
+        let client_tls = || {
+            TlsSettings::from_options(Some(&TlsConfig {
+                ca_file: Some(TEST_PEM_CA_PATH.into()),
+                alpn_protocols: Some(vec!["h2".into(), "http/1.1".into()]),
+                ..Default::default()
+            }))
+            .unwrap()
+        };
+
+        // A server which supports HTTP/2 negotiates it through ALPN.
+        let uri = spawn_https_server(TlsConfig {
+            alpn_protocols: Some(vec!["h2".into(), "http/1.1".into()]),
+            ..TlsConfig::test_config()
+        })
+        .await;
+        let client = HttpClient::new(client_tls(), &ProxyConfig::default()).unwrap();
+        let req = Request::get(&uri).body(Body::empty()).unwrap();
+        let response = client.send(req).await.unwrap();
+        assert_eq!(response.version(), Version::HTTP_2);
+
+        // Otherwise the client falls back to HTTP/1.1.
+        let uri = spawn_https_server(TlsConfig::test_config()).await;
+        let client = HttpClient::new(client_tls(), &ProxyConfig::default()).unwrap();
+        let req = Request::get(&uri).body(Body::empty()).unwrap();
+        let response = client.send(req).await.unwrap();
+        assert_eq!(response.version(), Version::HTTP_11);
+    }
+
+    /// Spawns an HTTP server, returning its address and a counter of accepted connections.
+    async fn spawn_counting_server() -> (SocketAddr, Arc<AtomicUsize>) {
+        let addr = next_addr();
+        let connections = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&connections);
+        let make_svc = make_service_fn(move |_| {
+            counter.fetch_add(1, Ordering::SeqCst);
+            async {
+                Ok::<_, Infallible>(tower::service_fn(|_req: Request<Body>| async {
+                    Ok::<Response<Body>, Infallible>(Response::new(Body::empty()))
+                }))
+            }
+        });
+        tokio::spawn(async move {
+            Server::bind(&addr).serve(make_svc).await.unwrap();
+        });
+
+        // Wait for the server to start.
+        tokio::time::sleep(Duration::from_millis(10)).await;
+
+        (addr, connections)
+    }
+
+    async fn connections_for_requests(
+        pool: HttpClientPoolConfig,
+        requests: usize,
+        pause: Duration,
+    ) -> usize {
+        let (addr, connections) = spawn_counting_server().await;
+        let client =
+            HttpClient::new_with_pool_config(None, &ProxyConfig::default(), &pool).unwrap();
+        for _ in 0..requests {
+            let req = Request::get(format!("http://{}/", addr))
+                .body(Body::empty())
+                .unwrap();
+            let response = client.send(req).await.unwrap();
+            hyper::body::to_bytes(response.into_body()).await.unwrap();
+
+            // The connection is returned to the pool by a spawned task, so the pause must be long
+            // enough to let it run before the next checkout.
+            tokio::time::sleep(pause).await;
+        }
+        connections.load(Ordering::SeqCst)
+    }
+
+    #[tokio::test]
+    async fn test_pool_config_applied() {
+        let pause = Duration::from_millis(10);
+
+        // By default the idle connection is reused by the second request.
+        assert_eq!(
+            connections_for_requests(HttpClientPoolConfig::default(), 2, pause).await,
+            1
+        );
+
+        // With no idle connections allowed, every request opens a new connection.
+        assert_eq!(
+            connections_for_requests(
+                HttpClientPoolConfig {
+                    max_idle_per_host: Some(0),
+                    ..Default::default()
+                },
+                2,
+                pause
+            )
+            .await,
+            2
+        );
+    }
+
+    #[tokio::test]
+    async fn test_pool_idle_timeout_applied() {
+        let pool = HttpClientPoolConfig {
+            idle_timeout_secs: Some(1),
+            ..Default::default()
+        };
+
+        // The idle connection is reused while it is younger than the idle timeout.
+        assert_eq!(
+            connections_for_requests(pool, 2, Duration::from_millis(10)).await,
+            1
+        );
+
+        // Once the idle timeout has passed, the second request opens a new connection.
+        assert_eq!(
+            connections_for_requests(pool, 2, Duration::from_millis(1500)).await,
+            2
+        );
+    }
+
+    #[tokio::test]
+    async fn test_pool_max_requests_per_connection_applied() {
+        let pool = HttpClientPoolConfig {
+            max_requests_per_connection: NonZeroUsize::new(2),
+            ..Default::default()
+        };
+        let pause = Duration::from_millis(10);
+
+        // The connection is reused until it has served the maximum number of requests.
+        assert_eq!(connections_for_requests(pool, 2, pause).await, 1);
+
+        // The next request after that opens a new connection.
+        assert_eq!(connections_for_requests(pool, 3, pause).await, 2);
+    }
+
+    #[test]
+    fn test_pool_config_parse() {
+        let config: HttpClientPoolConfig = toml::from_str(
+            "max_idle_per_host = 8\nidle_timeout_secs = 30\nmax_requests_per_connection = 100",
+        )
+        .unwrap();
+        assert_eq!(config.max_idle_per_host, Some(8));
+        assert_eq!(config.idle_timeout_secs, Some(30));
+        assert_eq!(config.max_requests_per_connection, NonZeroUsize::new(100));
+    }
 }
@@ -7,7 +7,7 @@ use vector_lib::{config::log_schema, schema};
 use vrl::value::Kind;
 
 use crate::{
-    http::{get_http_scheme_from_uri, HttpClient},
+    http::{get_http_scheme_from_uri, HttpClient, HttpClientPoolConfig},
     sinks::{
         prelude::*,
         util::{http::HttpStatusRetryLogic, RealtimeSizeBasedDefaultBatchSettings, UriSerde},
@@ -105,6 +105,10 @@ pub struct AzureMonitorLogsConfig {
     #[configurable(derived)]
     pub tls: Option<TlsConfig>,
 
+    #[configurable(derived)]
+    #[serde(default, skip_serializing_if = "crate::serde::is_default")]
+    pub pool: HttpClientPoolConfig,
+
     #[configurable(derived)]
     #[serde(
         default,
@@ -127,6 +131,7 @@ impl Default for AzureMonitorLogsConfig {
             request: Default::default(),
             time_generated_key: None,
             tls: None,
+            pool: Default::default(),
             acknowledgements: Default::default(),
         }
     }
@@ -167,7 +172,7 @@ impl AzureMonitorLogsConfig {
         let time_generated_key = self.get_time_generated_key();
 
         let tls_settings = TlsSettings::from_options(self.tls.as_ref())?;
-        let client = HttpClient::new(Some(tls_settings), &cx.proxy)?;
+        let client = HttpClient::new_with_pool_config(Some(tls_settings), &cx.proxy, &self.pool)?;
 
         let service = AzureMonitorLogsService::new(
             client,
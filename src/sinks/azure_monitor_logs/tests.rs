@@ -4,8 +4,9 @@ use futures::{future::ready, stream};
 use http::Response;
 use hyper::body;
 use openssl::{base64, hash, pkey, sign};
-use tokio::time::timeout;
+use tokio::{net::TcpListener, time::timeout};
 use vector_lib::config::log_schema;
+use vector_lib::event::{BatchNotifier, BatchStatus};
 
 use super::{
     config::{default_host, AzureMonitorLogsConfig},
@@ -15,8 +16,12 @@ use crate::{
     event::LogEvent,
     sinks::{prelude::*, util::encoding::Encoder},
     test_util::{
-        components::{run_and_assert_sink_compliance, SINK_TAGS},
+        components::{
+            run_and_assert_sink_compliance, run_and_assert_sink_error, COMPONENT_ERROR_TAGS,
+            SINK_TAGS,
+        },
         http::{always_200_response, spawn_blackhole_http_server},
+        next_addr,
     },
 };
 
@@ -46,6 +51,49 @@ async fn component_spec_compliance() {
     run_and_assert_sink_compliance(sink, stream::once(ready(event)), &SINK_TAGS).await;
 }
 
+#[tokio::test]
+async fn request_timeout_on_stalled_server() {
+    // Accept connections but never respond, as a server stalled mid-request would.
+    let addr = next_addr();
+    let listener = TcpListener::bind(&addr).await.unwrap();
+    tokio::spawn(async move {
+        let mut streams = Vec::new();
+        loop {
+            let (stream, _) = listener.accept().await.unwrap();
+            streams.push(stream);
+        }
+    });
+
+    let config: AzureMonitorLogsConfig = toml::from_str(
+        r#"
+            customer_id = "97ce69d9-b4be-4241-8dbd-d265edcf06c4"
+            shared_key = "ZnNkO2Zhc2RrbGZqYXNkaixmaG5tZXF3dWlsamtmYXNjZmouYXNkbmZrbHFhc2ZtYXNrbA=="
+            log_type = "Vector"
+            request.timeout_secs = 1
+            request.retry_attempts = 0
+        "#,
+    )
+    .unwrap();
+
+    let endpoint = format!("http://{}", addr).parse::<http::Uri>().unwrap();
+    let (sink, _healthcheck) = config
+        .build_inner(SinkContext::default(), endpoint.into())
+        .await
+        .unwrap();
+
+    let (batch, mut receiver) = BatchNotifier::new_with_receiver();
+    let event = Event::Log(LogEvent::from("simple message").with_batch_notifier(&batch));
+    drop(batch);
+
+    timeout(
+        Duration::from_secs(10),
+        run_and_assert_sink_error(sink, stream::once(ready(event)), &COMPONENT_ERROR_TAGS),
+    )
+    .await
+    .expect("request should time out rather than hang");
+    assert_eq!(receiver.try_recv(), Ok(BatchStatus::Rejected));
+}
+
 #[tokio::test]
 async fn fails_missing_creds() {
     let config: AzureMonitorLogsConfig = toml::from_str(
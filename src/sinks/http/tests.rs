@@ -390,6 +390,11 @@ async fn json_zlib_compression() {
     json_compression("zlib").await;
 }
 
+#[tokio::test]
+async fn json_brotli_compression() {
+    json_compression("brotli").await;
+}
+
 #[tokio::test]
 async fn json_gzip_compression_with_payload_wrapper() {
     json_compression_with_payload_wrapper("gzip").await;
@@ -451,6 +456,13 @@ async fn json_compression(compression: &str) {
                     Some(Authorization::basic("waldo", "hunter2")),
                     parts.headers.typed_get()
                 );
+                assert_eq!(
+                    Some(content_encoding(compression)),
+                    parts
+                        .headers
+                        .get("Content-Encoding")
+                        .map(|value| value.to_str().unwrap())
+                );
                 let lines: Vec<serde_json::Value> = parse_compressed_json(compression, body);
                 stream::iter(lines)
             })
@@ -536,6 +548,17 @@ where
         "gzip" => serde_json::from_reader(MultiGzDecoder::new(buf.reader())).unwrap(),
         "zstd" => serde_json::from_reader(zstd::Decoder::new(buf.reader()).unwrap()).unwrap(),
         "zlib" => serde_json::from_reader(ZlibDecoder::new(buf.reader())).unwrap(),
+        "brotli" => serde_json::from_reader(brotli::Decompressor::new(buf.reader(), 4096)).unwrap(),
+        _ => panic!("undefined compression: {}", compression),
+    }
+}
+
+fn content_encoding(compression: &str) -> &str {
+    match compression {
+        "gzip" => "gzip",
+        "zstd" => "zstd",
+        "zlib" => "deflate",
+        "brotli" => "br",
         _ => panic!("undefined compression: {}", compression),
     }
 }
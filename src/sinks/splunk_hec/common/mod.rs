@@ -13,6 +13,29 @@ pub(super) const INDEX_FIELD: &str = "index";
 pub(super) const HOST_FIELD: &str = "host";
 pub(super) const AUTO_EXTRACT_TIMESTAMP_FIELD: &str = "auto_extract_timestamp";
 
+/// Sums the `component_sent_bytes_total` counters whose `endpoint` tag starts with `endpoint`.
+#[cfg(test)]
+pub(super) fn sent_bytes_total(endpoint: &str) -> f64 {
+    use vector_lib::event::MetricValue;
+
+    crate::metrics::Controller::get()
+        .expect("There must be a controller")
+        .capture_metrics()
+        .into_iter()
+        .filter(|metric| metric.name() == "component_sent_bytes_total")
+        .filter(|metric| {
+            metric
+                .tags()
+                .and_then(|tags| tags.get("endpoint"))
+                .is_some_and(|tag| tag.starts_with(endpoint))
+        })
+        .map(|metric| match metric.value() {
+            MetricValue::Counter { value } => *value,
+            _ => 0.0,
+        })
+        .sum()
+}
+
 /// Splunk HEC endpoint configuration.
 #[configurable_component]
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
@@ -4,8 +4,8 @@ use vector_lib::stream::DriverResponse;
 
 pub struct HecResponse {
     pub event_status: EventStatus,
-    pub events_count: usize,
     pub events_byte_size: GroupedCountByteSize,
+    pub raw_byte_size: usize,
 }
 
 impl AsRef<EventStatus> for HecResponse {
@@ -22,4 +22,11 @@ impl DriverResponse for HecResponse {
     fn events_sent(&self) -> &GroupedCountByteSize {
         &self.events_byte_size
     }
+
+    // The HEC sinks don't register a protocol on the driver, so this is never reported: every
+    // successful request already emits `component_sent_bytes_total`, tagged with its endpoint,
+    // from `HttpBatchService`.
+    fn bytes_sent(&self) -> Option<usize> {
+        Some(self.raw_byte_size)
+    }
 }
@@ -114,7 +114,7 @@ where
         let ack_slot = self.current_ack_slot.take();
 
         let metadata = std::mem::take(req.metadata_mut());
-        let events_count = metadata.event_count();
+        let raw_byte_size = metadata.request_encoded_size();
         let events_byte_size = metadata.into_events_estimated_json_encoded_byte_size();
         let response = self.inner.call(req);
 
@@ -161,8 +161,8 @@ where
 
             Ok(HecResponse {
                 event_status,
-                events_count,
                 events_byte_size,
+                raw_byte_size,
             })
         })
     }
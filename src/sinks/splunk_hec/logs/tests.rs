@@ -1,6 +1,7 @@
 use std::{collections::BTreeMap, sync::Arc};
 
 use chrono::{TimeZone, Utc};
+use futures::{future::ready, stream};
 use futures_util::StreamExt;
 use serde::{de, Deserialize};
 use vector_lib::codecs::{JsonSerializerConfig, TextSerializerConfig};
@@ -23,13 +24,18 @@ use crate::{
     config::{SinkConfig, SinkContext},
     sinks::{
         splunk_hec::{
-            common::EndpointTarget,
+            common::{
+                acknowledgements::HecClientAcknowledgementsConfig, sent_bytes_total, EndpointTarget,
+            },
             logs::{config::HecLogsSinkConfig, encoder::HecLogsEncoder, sink::process_log},
         },
         util::{encoding::Encoder as _, test::build_test_server, Compression},
     },
     template::Template,
-    test_util::next_addr,
+    test_util::{
+        components::{run_and_assert_sink_compliance, HTTP_SINK_TAGS},
+        next_addr,
+    },
 };
 
 #[derive(Deserialize, Debug)]
@@ -388,3 +394,43 @@ fn splunk_encode_log_event_semantic_meanings() {
 
     assert_eq!(hec_data.host, Some("roast".to_string()));
 }
+
+#[tokio::test]
+async fn splunk_component_spec_compliance() {
+    let addr = next_addr();
+    let endpoint = format!("http://{}", addr);
+    let config = HecLogsSinkConfig {
+        default_token: "token".to_string().into(),
+        endpoint: endpoint.clone(),
+        host_key: None,
+        indexed_fields: Vec::new(),
+        index: None,
+        sourcetype: None,
+        source: None,
+        encoding: JsonSerializerConfig::default().into(),
+        compression: Compression::gzip_default(),
+        batch: Default::default(),
+        request: Default::default(),
+        tls: None,
+        acknowledgements: HecClientAcknowledgementsConfig {
+            indexer_acknowledgements_enabled: false,
+            ..Default::default()
+        },
+        timestamp_nanos_key: None,
+        timestamp_key: None,
+        auto_extract_timestamp: None,
+        endpoint_target: EndpointTarget::Event,
+    };
+
+    let (sink, _) = config.build(SinkContext::default()).await.unwrap();
+
+    let (rx, _trigger, server) = build_test_server(addr);
+    tokio::spawn(server);
+
+    let event = Event::Log(LogEvent::from("simple message"));
+    run_and_assert_sink_compliance(sink, stream::once(ready(event)), &HTTP_SINK_TAGS).await;
+
+    // The sent bytes are those of the compressed request body, counted once.
+    let (_, body) = rx.take(1).collect::<Vec<_>>().await.pop().unwrap();
+    assert_eq!(sent_bytes_total(&endpoint), body.len() as f64);
+}
@@ -1,6 +1,7 @@
 use std::{collections::BTreeSet, sync::Arc};
 
 use chrono::{DateTime, Utc};
+use futures::{future::ready, stream};
 use futures_util::StreamExt;
 use serde_json::{json, Value as JsonValue};
 use vector_lib::{
@@ -10,7 +11,9 @@ use vector_lib::{
 use vrl::owned_value_path;
 
 use super::sink::{process_metric, HecProcessedEvent};
-use crate::sinks::splunk_hec::common::config_host_key;
+use crate::sinks::splunk_hec::common::{
+    acknowledgements::HecClientAcknowledgementsConfig, config_host_key, sent_bytes_total,
+};
 use crate::{
     config::{SinkConfig, SinkContext},
     sinks::{
@@ -18,7 +21,10 @@ use crate::{
         util::{test::build_test_server, Compression},
     },
     template::Template,
-    test_util::next_addr,
+    test_util::{
+        components::{run_and_assert_sink_compliance, HTTP_SINK_TAGS},
+        next_addr,
+    },
 };
 
 fn get_counter() -> Metric {
@@ -363,3 +369,38 @@ async fn splunk_passthrough_token() {
         ]
     )
 }
+
+#[tokio::test]
+async fn splunk_component_spec_compliance() {
+    let addr = next_addr();
+    let endpoint = format!("http://{}", addr);
+    let config = HecMetricsSinkConfig {
+        default_token: "token".to_owned().into(),
+        endpoint: endpoint.clone(),
+        host_key: config_host_key(),
+        index: None,
+        sourcetype: None,
+        source: None,
+        compression: Compression::gzip_default(),
+        batch: Default::default(),
+        request: Default::default(),
+        tls: None,
+        acknowledgements: HecClientAcknowledgementsConfig {
+            indexer_acknowledgements_enabled: false,
+            ..Default::default()
+        },
+        default_namespace: None,
+    };
+
+    let (sink, _) = config.build(SinkContext::default()).await.unwrap();
+
+    let (rx, _trigger, server) = build_test_server(addr);
+    tokio::spawn(server);
+
+    let event = Event::from(get_counter());
+    run_and_assert_sink_compliance(sink, stream::once(ready(event)), &HTTP_SINK_TAGS).await;
+
+    // The sent bytes are those of the compressed request body, counted once.
+    let (_, body) = rx.take(1).collect::<Vec<_>>().await.pop().unwrap();
+    assert_eq!(sent_bytes_total(&endpoint), body.len() as f64);
+}
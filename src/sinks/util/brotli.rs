@@ -0,0 +1,94 @@
+use std::{fmt::Display, io};
+
+use super::buffer::compression::CompressionLevel;
+
+/// Size of the internal buffer used by the brotli encoder.
+const BROTLI_BUFFER_SIZE: usize = 4_096;
+
+/// Base-2 logarithm of the sliding window size, as recommended by the brotli reference encoder.
+const BROTLI_LG_WINDOW_SIZE: u32 = 22;
+
+#[derive(Debug)]
+pub struct BrotliCompressionLevel(u32);
+
+impl From<CompressionLevel> for BrotliCompressionLevel {
+    fn from(value: CompressionLevel) -> Self {
+        let val: u32 = match value {
+            CompressionLevel::None => 0,
+            // The brotli default of 11 is far too slow for streaming use, so we favor a level
+            // which is comparable to gzip's default in speed while still compressing better.
+            CompressionLevel::Default => 6,
+            CompressionLevel::Best => 11,
+            CompressionLevel::Fast => 1,
+            CompressionLevel::Val(v) => v.min(11),
+        };
+        BrotliCompressionLevel(val)
+    }
+}
+
+impl Display for BrotliCompressionLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+pub struct BrotliEncoder<W: io::Write> {
+    inner: brotli::CompressorWriter<W>,
+    bytes_written: usize,
+}
+
+impl<W: io::Write> BrotliEncoder<W> {
+    pub fn new(writer: W, level: BrotliCompressionLevel) -> Self {
+        let encoder = brotli::CompressorWriter::new(
+            writer,
+            BROTLI_BUFFER_SIZE,
+            level.0,
+            BROTLI_LG_WINDOW_SIZE,
+        );
+        Self {
+            inner: encoder,
+            bytes_written: 0,
+        }
+    }
+
+    /// Writes the final brotli metablock and returns the inner writer.
+    ///
+    /// The brotli encoder discards any error from writing the final metablock, so this is only
+    /// suitable for writers which cannot fail, such as `BytesMut`.
+    pub fn finish(self) -> W {
+        self.inner.into_inner()
+    }
+
+    pub fn get_ref(&self) -> &W {
+        self.inner.get_ref()
+    }
+
+    /// Returns whether any data has been written to the encoder.
+    ///
+    /// The encoder holds input back until it has a whole metablock, so the inner writer can stay
+    /// empty after data has been written.
+    pub const fn is_empty(&self) -> bool {
+        self.bytes_written == 0
+    }
+}
+
+impl<W: io::Write> io::Write for BrotliEncoder<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        #[allow(clippy::disallowed_methods)] // Caller handles the result of `write`.
+        let written = self.inner.write(buf)?;
+        self.bytes_written += written;
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+impl<W: io::Write + std::fmt::Debug> std::fmt::Debug for BrotliEncoder<W> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BrotliEncoder")
+            .field("inner", &self.get_ref())
+            .finish()
+    }
+}
@@ -13,7 +13,7 @@ use vector_lib::configurable::{
     Configurable, GenerateError, Metadata, ToValue,
 };
 
-use crate::sinks::util::zstd::ZstdCompressionLevel;
+use crate::sinks::util::{brotli::BrotliCompressionLevel, zstd::ZstdCompressionLevel};
 
 /// Compression configuration.
 #[derive(Copy, Clone, Debug, Derivative, Eq, PartialEq)]
@@ -42,6 +42,11 @@ pub enum Compression {
     ///
     /// [snappy]: https://github.com/google/snappy/blob/main/docs/README.md
     Snappy,
+
+    /// [Brotli][brotli] compression.
+    ///
+    /// [brotli]: https://github.com/google/brotli
+    Brotli(CompressionLevel),
 }
 
 impl Compression {
@@ -69,6 +74,10 @@ impl Compression {
         Compression::Zstd(CompressionLevel::const_default())
     }
 
+    pub const fn brotli_default() -> Compression {
+        Compression::Brotli(CompressionLevel::const_default())
+    }
+
     pub const fn content_encoding(self) -> Option<&'static str> {
         match self {
             Self::None => None,
@@ -76,6 +85,7 @@ impl Compression {
             Self::Zlib(_) => Some("deflate"),
             Self::Zstd(_) => Some("zstd"),
             Self::Snappy => Some("snappy"),
+            Self::Brotli(_) => Some("br"),
         }
     }
 
@@ -85,6 +95,7 @@ impl Compression {
             Self::Zlib(_) => Some("deflate"),
             Self::Zstd(_) => Some("zstd"),
             Self::Snappy => Some("snappy"),
+            Self::Brotli(_) => Some("br"),
             _ => None,
         }
     }
@@ -96,6 +107,7 @@ impl Compression {
             Self::Zlib(_) => "log.zz",
             Self::Zstd(_) => "log.zst",
             Self::Snappy => "log.snappy",
+            Self::Brotli(_) => "log.br",
         }
     }
 
@@ -106,13 +118,16 @@ impl Compression {
             Compression::Zlib(_) => 9,
             Compression::Zstd(_) => 21,
             Compression::Snappy => 0,
+            Compression::Brotli(_) => 11,
         }
     }
 
     pub const fn compression_level(self) -> CompressionLevel {
         match self {
             Self::None | Self::Snappy => CompressionLevel::None,
-            Self::Gzip(level) | Self::Zlib(level) | Self::Zstd(level) => level,
+            Self::Gzip(level) | Self::Zlib(level) | Self::Zstd(level) | Self::Brotli(level) => {
+                level
+            }
         }
     }
 }
@@ -127,6 +142,9 @@ impl fmt::Display for Compression {
                 write!(f, "zstd({})", ZstdCompressionLevel::from(*level))
             }
             Compression::Snappy => write!(f, "snappy"),
+            Compression::Brotli(ref level) => {
+                write!(f, "brotli({})", BrotliCompressionLevel::from(*level))
+            }
         }
     }
 }
@@ -155,9 +173,10 @@ impl<'de> de::Deserialize<'de> for Compression {
                     "zlib" => Ok(Compression::zlib_default()),
                     "zstd" => Ok(Compression::zstd_default()),
                     "snappy" => Ok(Compression::Snappy),
+                    "brotli" => Ok(Compression::brotli_default()),
                     _ => Err(de::Error::invalid_value(
                         de::Unexpected::Str(s),
-                        &r#""none" or "gzip" or "zlib" or "zstd""#,
+                        &r#""none" or "gzip" or "zlib" or "zstd" or "brotli""#,
                     )),
                 }
             }
@@ -202,9 +221,10 @@ impl<'de> de::Deserialize<'de> for Compression {
                         Some(_) => Err(de::Error::unknown_field("level", &[])),
                         None => Ok(Compression::Snappy),
                     },
+                    "brotli" => Ok(Compression::Brotli(level.unwrap_or_default())),
                     algorithm => Err(de::Error::unknown_variant(
                         algorithm,
-                        &["none", "gzip", "zlib", "zstd", "snappy"],
+                        &["none", "gzip", "zlib", "zstd", "snappy", "brotli"],
                     )),
                 }?;
 
@@ -268,6 +288,16 @@ impl ser::Serialize for Compression {
                 }
             }
             Compression::Snappy => serializer.serialize_str("snappy"),
+            Compression::Brotli(brotli_level) => {
+                if *brotli_level != CompressionLevel::Default {
+                    let mut map = serializer.serialize_map(None)?;
+                    map.serialize_entry("algorithm", "brotli")?;
+                    map.serialize_entry("level", &brotli_level)?;
+                    map.end()
+                } else {
+                    serializer.serialize_str("brotli")
+                }
+            }
         }
     }
 }
@@ -337,12 +367,19 @@ impl Configurable for Compression {
             "[snappy]: https://github.com/google/snappy/blob/main/docs/README.md",
         );
 
+        let brotli_string_subschema = generate_string_schema(
+            "Brotli",
+            Some("[Brotli][brotli] compression."),
+            "[brotli]: https://github.com/google/brotli",
+        );
+
         let mut all_string_oneof_subschema = generate_one_of_schema(&[
             none_string_subschema,
             gzip_string_subschema,
             zlib_string_subschema,
             zstd_string_subschema,
             snappy_string_subschema,
+            brotli_string_subschema,
         ]);
         apply_base_metadata(&mut all_string_oneof_subschema, string_metadata);
 
@@ -532,6 +569,10 @@ mod test {
             (r#""gzip""#, Compression::Gzip(CompressionLevel::default())),
             (r#""zlib""#, Compression::Zlib(CompressionLevel::default())),
             (r#""snappy""#, Compression::Snappy),
+            (
+                r#""brotli""#,
+                Compression::Brotli(CompressionLevel::default()),
+            ),
             (r#"{"algorithm": "none"}"#, Compression::None),
             (
                 r#"{"algorithm": "gzip"}"#,
@@ -557,6 +598,10 @@ mod test {
                 r#"{"algorithm": "zlib", "level": 8}"#,
                 Compression::Zlib(CompressionLevel::Val(8)),
             ),
+            (
+                r#"{"algorithm": "brotli", "level": 11}"#,
+                Compression::Brotli(CompressionLevel::Val(11)),
+            ),
         ];
         for (sources, result) in fixtures_valid.iter() {
             let deserialized: Result<Compression, _> = serde_json::from_str(sources);
@@ -570,11 +615,11 @@ mod test {
             ),
             (
                 r#""b42""#,
-                r#"invalid value: string "b42", expected "none" or "gzip" or "zlib" or "zstd" at line 1 column 5"#,
+                r#"invalid value: string "b42", expected "none" or "gzip" or "zlib" or "zstd" or "brotli" at line 1 column 5"#,
             ),
             (
                 r#"{"algorithm": "b42"}"#,
-                r"unknown variant `b42`, expected one of `none`, `gzip`, `zlib`, `zstd`, `snappy` at line 1 column 20",
+                r"unknown variant `b42`, expected one of `none`, `gzip`, `zlib`, `zstd`, `snappy`, `brotli` at line 1 column 20",
             ),
             (
                 r#"{"algorithm": "none", "level": "default"}"#,
@@ -604,6 +649,10 @@ mod test {
                 r#"{"algorithm": "zstd", "level": 22}"#,
                 r"invalid value `22`, expected value in range [0, 21] at line 1 column 34",
             ),
+            (
+                r#"{"algorithm": "brotli", "level": 12}"#,
+                r"invalid value `12`, expected value in range [0, 11] at line 1 column 36",
+            ),
             (
                 r#"{"algorithm": "snappy", "level": 3}"#,
                 r"unknown field `level`, there are no fields at line 1 column 35",
@@ -644,6 +693,8 @@ mod test {
             Compression::Zstd(CompressionLevel::default()),
             Compression::Zstd(CompressionLevel::Best),
             Compression::Zstd(CompressionLevel::Fast),
+            Compression::Brotli(CompressionLevel::Val(4)),
+            Compression::Brotli(CompressionLevel::default()),
         ];
 
         for v in fixtures_valid {
@@ -5,6 +5,7 @@ use flate2::write::{GzEncoder, ZlibEncoder};
 
 use super::{
     batch::{err_event_too_large, Batch, BatchSize, PushResult},
+    brotli::BrotliEncoder,
     snappy::SnappyEncoder,
     zstd::ZstdEncoder,
 };
@@ -34,6 +35,7 @@ pub enum InnerBuffer {
     Zlib(ZlibEncoder<bytes::buf::Writer<BytesMut>>),
     Zstd(ZstdEncoder<bytes::buf::Writer<BytesMut>>),
     Snappy(SnappyEncoder<bytes::buf::Writer<BytesMut>>),
+    Brotli(BrotliEncoder<bytes::buf::Writer<BytesMut>>),
 }
 
 impl Buffer {
@@ -65,6 +67,9 @@ impl Buffer {
                         .expect("Zstd encoder should not fail on init."),
                 ),
                 Compression::Snappy => InnerBuffer::Snappy(SnappyEncoder::new(writer)),
+                Compression::Brotli(level) => {
+                    InnerBuffer::Brotli(BrotliEncoder::new(writer, level.into()))
+                }
             }
         })
     }
@@ -85,6 +90,9 @@ impl Buffer {
                 inner.write_all(input).unwrap();
             }
             InnerBuffer::Snappy(inner) => inner.write_all(input).unwrap(),
+            InnerBuffer::Brotli(inner) => {
+                inner.write_all(input).unwrap();
+            }
         }
     }
 
@@ -97,6 +105,7 @@ impl Buffer {
                 InnerBuffer::Zlib(inner) => inner.get_ref().get_ref().is_empty(),
                 InnerBuffer::Zstd(inner) => inner.get_ref().get_ref().is_empty(),
                 InnerBuffer::Snappy(inner) => inner.is_empty(),
+                InnerBuffer::Brotli(inner) => inner.is_empty(),
             })
             .unwrap_or(true)
     }
@@ -151,6 +160,8 @@ impl Batch for Buffer {
                 .finish()
                 .expect("This can't fail because the inner writer is a Vec")
                 .into_inner(),
+            // Finishing can't fail because the inner writer is a Vec.
+            Some(InnerBuffer::Brotli(inner)) => inner.finish().into_inner(),
             None => BytesMut::new(),
         }
     }
@@ -234,4 +245,75 @@ mod test {
         .take(100_000)
         .flatten()));
     }
+
+    #[tokio::test]
+    async fn brotli() {
+        let sent_requests = Arc::new(Mutex::new(Vec::new()));
+
+        let svc = tower::service_fn(|req| {
+            let sent_requests = Arc::clone(&sent_requests);
+            sent_requests.lock().unwrap().push(req);
+            future::ok::<_, std::io::Error>(())
+        });
+
+        let mut batch_settings = BatchSettings::default();
+        batch_settings.size.bytes = 100_000;
+        batch_settings.size.events = 1_000;
+        batch_settings.timeout = Duration::from_secs(0);
+
+        let compression = Compression::brotli_default();
+        assert_eq!(compression.content_encoding(), Some("br"));
+
+        let buffered = BatchSink::new(
+            svc,
+            Buffer::new(batch_settings.size, compression),
+            batch_settings.timeout,
+        );
+
+        let input = std::iter::repeat(BytesMut::from(
+            "It's going down, I'm yelling timber, You better move, you better dance",
+        ))
+        .take(10_000);
+
+        buffered
+            .sink_map_err(drop)
+            .send_all(
+                &mut stream::iter(input)
+                    .map(|item| Ok(EncodedEvent::new(item, 0, JsonSize::zero()))),
+            )
+            .await
+            .unwrap();
+
+        let output = Arc::try_unwrap(sent_requests)
+            .unwrap()
+            .into_inner()
+            .unwrap();
+
+        assert!(output.len() > 1);
+        assert!(output.iter().map(|o| o.len()).sum::<usize>() < 8_000);
+
+        let decompressed = output.into_iter().flat_map(|batch| {
+            let mut decompressed = vec![];
+            brotli::Decompressor::new(batch.reader(), 4_096)
+                .read_to_end(&mut decompressed)
+                .unwrap();
+            decompressed
+        });
+
+        assert!(decompressed.eq(std::iter::repeat(
+            b"It's going down, I'm yelling timber, You better move, you better dance".to_vec()
+        )
+        .take(10_000)
+        .flatten()));
+    }
+
+    #[test]
+    fn brotli_small_batch_is_not_empty() {
+        let mut buffer = Buffer::new(BatchSettings::default().size, Compression::brotli_default());
+        buffer.push(b"I am a potato");
+
+        // The encoder holds back input smaller than a metablock, so nothing has reached the
+        // underlying buffer yet.
+        assert!(!buffer.is_empty());
+    }
 }
@@ -3,7 +3,7 @@ use std::{io, io::BufWriter};
 use bytes::{BufMut, BytesMut};
 use flate2::write::{GzEncoder, ZlibEncoder};
 
-use super::{snappy::SnappyEncoder, zstd::ZstdEncoder, Compression};
+use super::{brotli::BrotliEncoder, snappy::SnappyEncoder, zstd::ZstdEncoder, Compression};
 
 const GZIP_INPUT_BUFFER_CAPACITY: usize = 4_096;
 const ZLIB_INPUT_BUFFER_CAPACITY: usize = 4_096;
@@ -16,6 +16,7 @@ enum Writer {
     Zlib(BufWriter<ZlibEncoder<bytes::buf::Writer<BytesMut>>>),
     Zstd(ZstdEncoder<bytes::buf::Writer<BytesMut>>),
     Snappy(SnappyEncoder<bytes::buf::Writer<BytesMut>>),
+    Brotli(BrotliEncoder<bytes::buf::Writer<BytesMut>>),
 }
 
 impl Writer {
@@ -26,6 +27,7 @@ impl Writer {
             Writer::Zlib(inner) => inner.get_ref().get_ref().get_ref(),
             Writer::Zstd(inner) => inner.get_ref().get_ref(),
             Writer::Snappy(inner) => inner.get_ref().get_ref(),
+            Writer::Brotli(inner) => inner.get_ref().get_ref(),
         }
     }
 
@@ -48,6 +50,8 @@ impl Writer {
             Writer::Snappy(writer) => writer
                 .finish()
                 .expect("snappy writer should not fail to finish"),
+            // Finishing can't fail because the inner writer is a `BytesMut`.
+            Writer::Brotli(writer) => writer.finish(),
         }
         .into_inner()
     }
@@ -59,6 +63,8 @@ impl Writer {
             Writer::Zlib(writer) => writer.into_inner()?.finish()?,
             Writer::Zstd(writer) => writer.finish()?,
             Writer::Snappy(writer) => writer.finish()?,
+            // Finishing can't fail because the inner writer is a `BytesMut`.
+            Writer::Brotli(writer) => writer.finish(),
         }
         .into_inner();
 
@@ -91,6 +97,7 @@ impl From<Compression> for Writer {
                 Writer::Zstd(encoder)
             }
             Compression::Snappy => Writer::Snappy(SnappyEncoder::new(writer)),
+            Compression::Brotli(level) => Writer::Brotli(BrotliEncoder::new(writer, level.into())),
         }
     }
 }
@@ -104,6 +111,7 @@ impl io::Write for Writer {
             Writer::Zlib(writer) => writer.write(buf),
             Writer::Zstd(writer) => writer.write(buf),
             Writer::Snappy(writer) => writer.write(buf),
+            Writer::Brotli(writer) => writer.write(buf),
         }
     }
 
@@ -114,6 +122,7 @@ impl io::Write for Writer {
             Writer::Zlib(writer) => writer.flush(),
             Writer::Zstd(writer) => writer.flush(),
             Writer::Snappy(writer) => writer.flush(),
+            Writer::Brotli(writer) => writer.flush(),
         }
     }
 }
@@ -187,3 +196,35 @@ impl From<Compression> for Compressor {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use super::Compressor;
+    use crate::sinks::util::{buffer::compression::CompressionLevel, Compression};
+
+    fn gzip_compressed_len(level: u32, input: &str) -> usize {
+        let mut compressor = Compressor::from(Compression::Gzip(CompressionLevel::Val(level)));
+        compressor.write_all(input.as_bytes()).unwrap();
+        compressor.finish().unwrap().len()
+    }
+
+    #[test]
+    fn gzip_level_affects_output_size() {
+        let input = (0..10_000)
+            .map(|i| {
+                format!(
+                    r#"{{"message":"request {i} served","took_ms":{}}}"#,
+                    i % 997
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let fast = gzip_compressed_len(1, &input);
+        let best = gzip_compressed_len(9, &input);
+
+        assert!(best < fast, "level 9 ({best}) should beat level 1 ({fast})");
+    }
+}
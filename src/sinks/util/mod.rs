@@ -3,6 +3,7 @@ pub mod auth;
 // https://github.com/mcarton/rust-derivative/issues/112
 #[allow(clippy::non_canonical_clone_impl)]
 pub mod batch;
+pub mod brotli;
 pub mod buffer;
 pub mod builder;
 pub mod compressor;
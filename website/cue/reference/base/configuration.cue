@@ -442,6 +442,21 @@ base: configuration: configuration: {
 														"""
 						required: false
 					}
+					min_tls_version: {
+						type: string: enum: {
+							"TLSv1.0": "TLS 1.0."
+							"TLSv1.1": "TLS 1.1."
+							"TLSv1.2": "TLS 1.2."
+							"TLSv1.3": "TLS 1.3."
+						}
+						description: """
+														Sets the minimum TLS protocol version.
+
+														Peers that cannot negotiate at least this version are rejected during the handshake. If
+														unset, the default minimum of the TLS library is used.
+														"""
+						required: false
+					}
 					server_name: {
 						type: string: examples: ["www.example.com"]
 						description: """
@@ -186,6 +186,11 @@ base: components: sinks: aws_kinesis_streams: configuration: {
 		type: string: {
 			default: "none"
 			enum: {
+				brotli: """
+					[Brotli][brotli] compression.
+
+					[brotli]: https://github.com/google/brotli
+					"""
 				gzip: """
 					[Gzip][gzip] compression.
 
@@ -830,6 +835,21 @@ base: components: sinks: aws_kinesis_streams: configuration: {
 				required: false
 				type: string: examples: ["${KEY_PASS_ENV_VAR}", "PassWord1"]
 			}
+			min_tls_version: {
+				description: """
+					Sets the minimum TLS protocol version.
+
+					Peers that cannot negotiate at least this version are rejected during the handshake. If
+					unset, the default minimum of the TLS library is used.
+					"""
+				required: false
+				type: string: enum: {
+					"TLSv1.0": "TLS 1.0."
+					"TLSv1.1": "TLS 1.1."
+					"TLSv1.2": "TLS 1.2."
+					"TLSv1.3": "TLS 1.3."
+				}
+			}
 			server_name: {
 				description: """
 					Server name to use when using Server Name Indication (SNI).
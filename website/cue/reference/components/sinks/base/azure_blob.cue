@@ -124,6 +124,11 @@ base: components: sinks: azure_blob: configuration: {
 		type: string: {
 			default: "gzip"
 			enum: {
+				brotli: """
+					[Brotli][brotli] compression.
+
+					[brotli]: https://github.com/google/brotli
+					"""
 				gzip: """
 					[Gzip][gzip] compression.
 
@@ -128,6 +128,44 @@ base: components: sinks: azure_monitor_logs: configuration: {
 		required: true
 		type: string: examples: ["MyTableName", "MyRecordType"]
 	}
+	pool: {
+		description: "Configuration of the HTTP client connection pool."
+		required:    false
+		type: object: options: {
+			idle_timeout_secs: {
+				description: """
+					The amount of time an idle connection is kept open before it is closed.
+
+					If unset, idle connections are closed after 90 seconds.
+					"""
+				required: false
+				type: uint: {
+					examples: [30]
+					unit: "seconds"
+				}
+			}
+			max_idle_per_host: {
+				description: """
+					The maximum number of idle connections kept open per host.
+
+					Idle connections are reused by later requests, avoiding the cost of a new TCP connection
+					and TLS handshake. If unset, the number of idle connections is not limited.
+					"""
+				required: false
+				type: uint: examples: [16]
+			}
+			max_requests_per_connection: {
+				description: """
+					The maximum number of requests sent over a connection before it is closed.
+
+					Closing connections periodically avoids errors from long-lived connections that have gone
+					stale, such as ones reset by the server. If unset, connections are reused indefinitely.
+					"""
+				required: false
+				type: uint: examples: [1000]
+			}
+		}
+	}
 	request: {
 		description: """
 			Middleware settings for outbound requests.
@@ -390,6 +428,21 @@ base: components: sinks: azure_monitor_logs: configuration: {
 				required: false
 				type: string: examples: ["${KEY_PASS_ENV_VAR}", "PassWord1"]
 			}
+			min_tls_version: {
+				description: """
+					Sets the minimum TLS protocol version.
+
+					Peers that cannot negotiate at least this version are rejected during the handshake. If
+					unset, the default minimum of the TLS library is used.
+					"""
+				required: false
+				type: string: enum: {
+					"TLSv1.0": "TLS 1.0."
+					"TLSv1.1": "TLS 1.1."
+					"TLSv1.2": "TLS 1.2."
+					"TLSv1.3": "TLS 1.3."
+				}
+			}
 			server_name: {
 				description: """
 					Server name to use when using Server Name Indication (SNI).
@@ -70,6 +70,11 @@ base: components: sinks: honeycomb: configuration: {
 		type: string: {
 			default: "zstd"
 			enum: {
+				brotli: """
+					[Brotli][brotli] compression.
+
+					[brotli]: https://github.com/google/brotli
+					"""
 				gzip: """
 					[Gzip][gzip] compression.
 